@@ -1,5 +1,6 @@
-use bevy_utils::Uuid;
+use bevy_utils::{HashMap, Uuid};
 use std::ops::Deref;
+use wgpu::{Extent3d, TextureDescriptor, TextureFormat, TextureUsages};
 
 use crate::render_resource::resource_macros::*;
 
@@ -17,6 +18,7 @@ render_resource_wrapper!(ErasedTexture, wgpu::Texture);
 pub struct Texture {
     id: TextureId,
     value: ErasedTexture,
+    descriptor: TextureDescriptor<'static>,
 }
 
 impl Texture {
@@ -30,17 +32,171 @@ impl Texture {
     pub fn create_view(&self, desc: &wgpu::TextureViewDescriptor) -> TextureView {
         TextureView::from(self.value.create_view(desc))
     }
+
+    /// Creates a [`Texture`] from a raw wgpu [`Texture`](wgpu::Texture) along with the
+    /// [`TextureDescriptor`] it was created from, so size/format/usage can be queried later
+    /// without round-tripping to wgpu.
+    pub fn from_descriptor(value: wgpu::Texture, descriptor: TextureDescriptor<'static>) -> Self {
+        Texture {
+            id: TextureId(Uuid::new_v4()),
+            value: ErasedTexture::new(value),
+            descriptor,
+        }
+    }
+
+    /// Returns the [`TextureDescriptor`] this texture was created from.
+    #[inline]
+    pub fn descriptor(&self) -> &TextureDescriptor<'static> {
+        &self.descriptor
+    }
+
+    /// Returns the size of this texture.
+    #[inline]
+    pub fn size(&self) -> Extent3d {
+        self.descriptor.size
+    }
+
+    /// Returns the [`TextureFormat`] of this texture.
+    #[inline]
+    pub fn format(&self) -> TextureFormat {
+        self.descriptor.format
+    }
+
+    /// Returns the number of mip levels of this texture.
+    #[inline]
+    pub fn mip_level_count(&self) -> u32 {
+        self.descriptor.mip_level_count
+    }
+
+    /// Returns the sample count of this texture.
+    #[inline]
+    pub fn sample_count(&self) -> u32 {
+        self.descriptor.sample_count
+    }
+
+    /// Returns the [`TextureUsages`] of this texture.
+    #[inline]
+    pub fn usage(&self) -> TextureUsages {
+        self.descriptor.usage
+    }
+
+    /// Creates a view of this texture with an overridden [`TextureFormat`], aliasing it as a
+    /// sibling format (e.g. viewing an sRGB texture as linear, or vice versa). The `desc.format`
+    /// field is overwritten with `format`.
+    ///
+    /// `format` must either equal this texture's own format, or have been included in
+    /// [`TextureDescriptor::view_formats`] when the texture was created — wgpu only allows
+    /// aliasing a view to a format the texture was explicitly created to support. Panics
+    /// otherwise, rather than letting the mismatch surface later as an opaque wgpu validation
+    /// error.
+    pub fn create_view_with_format(
+        &self,
+        desc: &wgpu::TextureViewDescriptor,
+        format: TextureFormat,
+    ) -> TextureView {
+        assert!(
+            format == self.format() || self.descriptor.view_formats.contains(&format),
+            "cannot create a {format:?} view of a {:?} texture: {format:?} must be included in \
+             TextureDescriptor::view_formats when the texture is created",
+            self.format(),
+        );
+
+        let mut desc = desc.clone();
+        desc.format = Some(format);
+        let mut view = TextureView::from(self.value.create_view(&desc));
+        view.format = Some(format);
+        view
+    }
+
+    /// Creates a view of this texture aliased as the sRGB sibling of its own format.
+    ///
+    /// Panics if this texture's format has no sRGB sibling, or if the sibling format wasn't
+    /// included in `TextureDescriptor::view_formats` when the texture was created (see
+    /// [`create_view_with_format`](Self::create_view_with_format)).
+    pub fn create_srgb_view(&self, desc: &wgpu::TextureViewDescriptor) -> TextureView {
+        let format = srgb_format(self.format())
+            .unwrap_or_else(|| panic!("{:?} has no sRGB sibling format", self.format()));
+        self.create_view_with_format(desc, format)
+    }
+
+    /// Creates a view of this texture aliased as the linear sibling of its own format.
+    ///
+    /// Panics if this texture's format has no linear sibling, or if the sibling format wasn't
+    /// included in `TextureDescriptor::view_formats` when the texture was created (see
+    /// [`create_view_with_format`](Self::create_view_with_format)).
+    pub fn create_linear_view(&self, desc: &wgpu::TextureViewDescriptor) -> TextureView {
+        let format = linear_format(self.format())
+            .unwrap_or_else(|| panic!("{:?} has no linear sibling format", self.format()));
+        self.create_view_with_format(desc, format)
+    }
+}
+
+/// Returns the sRGB variant of `format`, or `None` if it has no sRGB sibling.
+fn srgb_format(format: TextureFormat) -> Option<TextureFormat> {
+    Some(match format {
+        TextureFormat::Rgba8Unorm => TextureFormat::Rgba8UnormSrgb,
+        TextureFormat::Bgra8Unorm => TextureFormat::Bgra8UnormSrgb,
+        TextureFormat::Bc1RgbaUnorm => TextureFormat::Bc1RgbaUnormSrgb,
+        TextureFormat::Bc2RgbaUnorm => TextureFormat::Bc2RgbaUnormSrgb,
+        TextureFormat::Bc3RgbaUnorm => TextureFormat::Bc3RgbaUnormSrgb,
+        TextureFormat::Bc7RgbaUnorm => TextureFormat::Bc7RgbaUnormSrgb,
+        TextureFormat::Etc2Rgb8Unorm => TextureFormat::Etc2Rgb8UnormSrgb,
+        TextureFormat::Etc2Rgb8A1Unorm => TextureFormat::Etc2Rgb8A1UnormSrgb,
+        TextureFormat::Etc2Rgba8Unorm => TextureFormat::Etc2Rgba8UnormSrgb,
+        _ => return None,
+    })
+}
+
+/// Returns the linear (non-sRGB) variant of `format`, or `None` if it has no linear sibling.
+fn linear_format(format: TextureFormat) -> Option<TextureFormat> {
+    Some(match format {
+        TextureFormat::Rgba8UnormSrgb => TextureFormat::Rgba8Unorm,
+        TextureFormat::Bgra8UnormSrgb => TextureFormat::Bgra8Unorm,
+        TextureFormat::Bc1RgbaUnormSrgb => TextureFormat::Bc1RgbaUnorm,
+        TextureFormat::Bc2RgbaUnormSrgb => TextureFormat::Bc2RgbaUnorm,
+        TextureFormat::Bc3RgbaUnormSrgb => TextureFormat::Bc3RgbaUnorm,
+        TextureFormat::Bc7RgbaUnormSrgb => TextureFormat::Bc7RgbaUnorm,
+        TextureFormat::Etc2Rgb8UnormSrgb => TextureFormat::Etc2Rgb8Unorm,
+        TextureFormat::Etc2Rgb8A1UnormSrgb => TextureFormat::Etc2Rgb8A1Unorm,
+        TextureFormat::Etc2Rgba8UnormSrgb => TextureFormat::Etc2Rgba8Unorm,
+        _ => return None,
+    })
 }
 
 impl From<wgpu::Texture> for Texture {
+    /// Wraps a raw wgpu [`Texture`](wgpu::Texture) without any descriptor metadata.
+    ///
+    /// Prefer [`Texture::from_descriptor`] when the [`TextureDescriptor`] used to create the
+    /// texture is available, since it enables querying size/format/usage later without
+    /// round-tripping to wgpu. Textures wrapped this way report a 1x1 `Rgba8UnormSrgb`
+    /// placeholder with empty usage from `size()`/`format()`/`usage()`.
     fn from(value: wgpu::Texture) -> Self {
         Texture {
             id: TextureId(Uuid::new_v4()),
             value: ErasedTexture::new(value),
+            descriptor: unknown_texture_descriptor(),
         }
     }
 }
 
+/// A placeholder [`TextureDescriptor`] used when a [`Texture`] is constructed without one.
+fn unknown_texture_descriptor() -> TextureDescriptor<'static> {
+    TextureDescriptor {
+        label: None,
+        size: Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: TextureFormat::Rgba8UnormSrgb,
+        usage: TextureUsages::empty(),
+        view_formats: &[],
+    }
+}
+
 impl Deref for Texture {
     type Target = wgpu::Texture;
 
@@ -82,6 +238,10 @@ pub enum TextureViewValue {
 pub struct TextureView {
     id: TextureViewId,
     value: TextureViewValue,
+    /// The format passed to [`Texture::create_view_with_format`] when this view was created
+    /// through it (even if that is the texture's own format). `None` for views created any
+    /// other way.
+    format: Option<TextureFormat>,
 }
 
 impl TextureView {
@@ -99,6 +259,15 @@ impl TextureView {
             TextureViewValue::SurfaceTexture { texture, .. } => texture.try_unwrap(),
         }
     }
+
+    /// Returns the [`TextureFormat`] this view was explicitly created with, if it was created via
+    /// [`Texture::create_view_with_format`] (or `create_srgb_view`/`create_linear_view`), so
+    /// downstream bind-group/pipeline code can validate view-format compatibility. Returns `None`
+    /// for views created any other way (e.g. [`Texture::create_view`]), whatever their format.
+    #[inline]
+    pub fn format(&self) -> Option<TextureFormat> {
+        self.format
+    }
 }
 
 impl From<wgpu::TextureView> for TextureView {
@@ -106,6 +275,7 @@ impl From<wgpu::TextureView> for TextureView {
         TextureView {
             id: TextureViewId(Uuid::new_v4()),
             value: TextureViewValue::TextureView(ErasedTextureView::new(value)),
+            format: None,
         }
     }
 }
@@ -118,6 +288,7 @@ impl From<wgpu::SurfaceTexture> for TextureView {
         TextureView {
             id: TextureViewId(Uuid::new_v4()),
             value: TextureViewValue::SurfaceTexture { texture, view },
+            format: None,
         }
     }
 }
@@ -176,3 +347,534 @@ impl Deref for Sampler {
         &self.value
     }
 }
+
+/// The canonical depth format used by [`SampledTexture::depth`].
+const SAMPLED_TEXTURE_DEPTH_FORMAT: TextureFormat = TextureFormat::Depth32Float;
+
+/// A [`Texture`] bundled with its default [`TextureView`], a [`Sampler`], and an optional
+/// [`BindGroup`](super::BindGroup) (and the [`BindGroupLayout`](super::BindGroupLayout) it was
+/// created from).
+///
+/// This groups together the texture + view + sampler + bind group boilerplate that almost every
+/// sampled texture needs, while still exposing the low-level [`Texture`], [`TextureView`], and
+/// [`Sampler`] types for cases that need finer control.
+#[derive(Clone, Debug)]
+pub struct SampledTexture {
+    texture: Texture,
+    view: TextureView,
+    sampler: Sampler,
+    bind_group_layout: Option<super::BindGroupLayout>,
+    bind_group: Option<super::BindGroup>,
+}
+
+impl SampledTexture {
+    /// Creates a [`SampledTexture`] from a [`TextureDescriptor`] and a [`SamplerDescriptor`],
+    /// without a bind group.
+    pub fn new(
+        device: &crate::renderer::RenderDevice,
+        texture_descriptor: &TextureDescriptor<'static>,
+        sampler_descriptor: &wgpu::SamplerDescriptor,
+    ) -> Self {
+        let texture = device.create_texture(texture_descriptor);
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(sampler_descriptor);
+
+        SampledTexture {
+            texture,
+            view,
+            sampler,
+            bind_group_layout: None,
+            bind_group: None,
+        }
+    }
+
+    /// Creates a [`SampledTexture`] suitable for use as a depth attachment: the canonical depth
+    /// format, `RENDER_ATTACHMENT | TEXTURE_BINDING` usage, and the given `sample_count`.
+    pub fn depth(
+        device: &crate::renderer::RenderDevice,
+        size: Extent3d,
+        sample_count: u32,
+    ) -> Self {
+        Self::new(
+            device,
+            &TextureDescriptor {
+                label: Some("sampled_texture_depth"),
+                size,
+                mip_level_count: 1,
+                sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format: SAMPLED_TEXTURE_DEPTH_FORMAT,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+            &wgpu::SamplerDescriptor::default(),
+        )
+    }
+
+    /// Creates a [`SampledTexture`] suitable for use as a color attachment, in the given
+    /// `format`, with `RENDER_ATTACHMENT | TEXTURE_BINDING` usage.
+    pub fn color(device: &crate::renderer::RenderDevice, size: Extent3d, format: TextureFormat) -> Self {
+        Self::new(
+            device,
+            &TextureDescriptor {
+                label: Some("sampled_texture_color"),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+            &wgpu::SamplerDescriptor::default(),
+        )
+    }
+
+    /// Returns the underlying [`Texture`].
+    #[inline]
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Returns the default [`TextureView`] of this texture.
+    #[inline]
+    pub fn view(&self) -> &TextureView {
+        &self.view
+    }
+
+    /// Returns the [`Sampler`] of this texture.
+    #[inline]
+    pub fn sampler(&self) -> &Sampler {
+        &self.sampler
+    }
+
+    /// Returns the cached [`BindGroup`](super::BindGroup), if one was created for this texture.
+    #[inline]
+    pub fn bind_group(&self) -> Option<&super::BindGroup> {
+        self.bind_group.as_ref()
+    }
+
+    /// Returns the [`BindGroupLayout`](super::BindGroupLayout) the bind group was created from,
+    /// if any.
+    #[inline]
+    pub fn bind_group_layout(&self) -> Option<&super::BindGroupLayout> {
+        self.bind_group_layout.as_ref()
+    }
+
+    /// Sets the cached bind group and the layout it was created from.
+    pub fn with_bind_group(
+        mut self,
+        layout: super::BindGroupLayout,
+        bind_group: super::BindGroup,
+    ) -> Self {
+        self.bind_group_layout = Some(layout);
+        self.bind_group = Some(bind_group);
+        self
+    }
+}
+
+/// The error returned by [`MipmapGenerator::generate_mipmaps`] when `texture` doesn't meet its
+/// preconditions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum MipmapGenerationError {
+    /// The texture's usage doesn't include `RENDER_ATTACHMENT | TEXTURE_BINDING`, so it can't be
+    /// used as a render attachment (for the downsample writes) or sampled from (for the reads).
+    #[error(
+        "texture usage {usage:?} is missing RENDER_ATTACHMENT | TEXTURE_BINDING, required to generate mipmaps"
+    )]
+    MissingUsage {
+        /// The texture's actual usage flags.
+        usage: TextureUsages,
+    },
+
+    /// The texture's descriptor specifies only one mip level, so there is nothing to generate.
+    #[error("texture has only one mip level, so there are no mips to generate")]
+    NoMipLevels,
+
+    /// The texture's format can't be used as a filterable, render-attachable color target (e.g.
+    /// it's a depth/stencil format, or an integer/non-filterable-float format).
+    #[error(
+        "texture format {format:?} doesn't support mipmap generation: it must be a color format \
+         with a filterable float sample type"
+    )]
+    UnsupportedFormat {
+        /// The texture's actual format.
+        format: TextureFormat,
+    },
+}
+
+/// Returns whether `format` can be used with [`MipmapGenerator::generate_mipmaps`]: it must be a
+/// color format with a filterable float sample type, since it's bound both as a
+/// `RENDER_ATTACHMENT` color target and as a filtered texture binding. Depth/stencil formats and
+/// non-filterable formats (integer formats, or float formats that require
+/// `Features::FLOAT32_FILTERABLE`) aren't supported.
+fn supports_mipmap_generation(format: TextureFormat) -> bool {
+    matches!(
+        format,
+        TextureFormat::R8Unorm
+            | TextureFormat::R16Float
+            | TextureFormat::Rg8Unorm
+            | TextureFormat::Rg16Float
+            | TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb
+            | TextureFormat::Rgba16Float
+            | TextureFormat::Rgb10a2Unorm
+    )
+}
+
+/// Floors `dim >> level` to at least 1, matching how wgpu computes a mip level's size for a
+/// non-power-of-two base size.
+fn floored_mip_size(dim: u32, level: u32) -> u32 {
+    (dim >> level).max(1)
+}
+
+/// Generates mipmaps for a [`Texture`] via a simple fullscreen linear-downsample blit, run once
+/// per mip level from the base level down to the last.
+///
+/// Pipelines are cached per [`TextureFormat`] so textures that share a format only pay the
+/// pipeline-creation cost the first time they're mipmapped.
+pub struct MipmapGenerator {
+    pipelines: HashMap<TextureFormat, super::RenderPipeline>,
+    bind_group_layout: super::BindGroupLayout,
+    sampler: Sampler,
+}
+
+impl MipmapGenerator {
+    /// Creates a new, empty generator. A linear [`Sampler`] and the bind group layout shared by
+    /// every cached pipeline are created up front; per-format render pipelines are created lazily
+    /// the first time [`generate_mipmaps`](Self::generate_mipmaps) sees that format.
+    pub fn new(device: &crate::renderer::RenderDevice) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("mipmap_generator_sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("mipmap_generator_bind_group_layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        MipmapGenerator {
+            pipelines: Default::default(),
+            bind_group_layout,
+            sampler,
+        }
+    }
+
+    /// Generates every mip level of `texture` above level 0 by repeatedly blitting each level
+    /// into the next, sampling the previous level through a linear [`Sampler`]. Non-power-of-two
+    /// sizes are handled by flooring each dimension to `max(1, dim >> level)`.
+    ///
+    /// Returns an error, rather than silently doing nothing, if `texture`'s usage doesn't
+    /// include `RENDER_ATTACHMENT | TEXTURE_BINDING` or if its descriptor specifies only one mip
+    /// level.
+    pub fn generate_mipmaps(
+        &mut self,
+        device: &crate::renderer::RenderDevice,
+        queue: &crate::renderer::RenderQueue,
+        texture: &Texture,
+    ) -> Result<(), MipmapGenerationError> {
+        let required_usage = TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING;
+        if !texture.usage().contains(required_usage) {
+            return Err(MipmapGenerationError::MissingUsage {
+                usage: texture.usage(),
+            });
+        }
+
+        let mip_level_count = texture.mip_level_count();
+        if mip_level_count <= 1 {
+            return Err(MipmapGenerationError::NoMipLevels);
+        }
+
+        let format = texture.format();
+        if !supports_mipmap_generation(format) {
+            return Err(MipmapGenerationError::UnsupportedFormat { format });
+        }
+
+        let pipeline = self
+            .pipelines
+            .entry(format)
+            .or_insert_with(|| Self::create_pipeline(device, &self.bind_group_layout, format));
+
+        let size = texture.size();
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("generate_mipmaps"),
+        });
+
+        for level in 1..mip_level_count {
+            let src_view = texture.create_view(&wgpu::TextureViewDescriptor {
+                base_mip_level: level - 1,
+                mip_level_count: Some(1),
+                ..Default::default()
+            });
+            let dst_view = texture.create_view(&wgpu::TextureViewDescriptor {
+                base_mip_level: level,
+                mip_level_count: Some(1),
+                ..Default::default()
+            });
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("mipmap_generator_bind_group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&src_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                ],
+            });
+
+            let width = floored_mip_size(size.width, level);
+            let height = floored_mip_size(size.height, level);
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("mipmap_generator_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &dst_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+            pass.set_viewport(0.0, 0.0, width as f32, height as f32, 0.0, 1.0);
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+
+        Ok(())
+    }
+
+    fn create_pipeline(
+        device: &crate::renderer::RenderDevice,
+        bind_group_layout: &super::BindGroupLayout,
+        format: TextureFormat,
+    ) -> super::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mipmap_generator_shader"),
+            source: wgpu::ShaderSource::Wgsl(MIPMAP_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("mipmap_generator_pipeline_layout"),
+            bind_group_layouts: &[bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("mipmap_generator_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vertex",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fragment",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        })
+    }
+}
+
+/// Fullscreen triangle vertex shader paired with a single linear-sample fragment shader, used by
+/// [`MipmapGenerator`] to downsample one mip level into the next.
+const MIPMAP_SHADER: &str = r#"
+@group(0) @binding(0) var src_texture: texture_2d<f32>;
+@group(0) @binding(1) var src_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+    out.uv = uv;
+    // wgpu's v=0 is the top row, but NDC y is up, so flip y here rather than sampling upside-down.
+    out.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(src_texture, src_sampler, in.uv);
+}
+"#;
+
+impl Texture {
+    /// Uploads `image` as a GPU texture in one call, with a default usage of
+    /// `COPY_SRC | COPY_DST | TEXTURE_BINDING | RENDER_ATTACHMENT`.
+    ///
+    /// `format` overrides the format the texture is created with; pass `None` to default to
+    /// `Rgba8UnormSrgb`.
+    ///
+    /// The `image` crate is an unconditional dependency of this crate (individual codecs are
+    /// what's feature-gated, e.g. `png`/`hdr`/`exr`, not `image` support itself), so this isn't
+    /// gated behind a crate feature.
+    pub fn from_image(
+        device: &crate::renderer::RenderDevice,
+        queue: &crate::renderer::RenderQueue,
+        image: &image::DynamicImage,
+        format: Option<TextureFormat>,
+    ) -> Self {
+        let rgba = image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+
+        let descriptor = TextureDescriptor {
+            label: Some("texture_from_image"),
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: format.unwrap_or(TextureFormat::Rgba8UnormSrgb),
+            usage: TextureUsages::COPY_SRC
+                | TextureUsages::COPY_DST
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        };
+
+        let texture = device.create_texture(&descriptor);
+
+        queue.write_texture(
+            texture.as_image_copy(),
+            &rgba,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * width),
+                rows_per_image: Some(height),
+            },
+            descriptor.size,
+        );
+
+        texture
+    }
+
+    /// Decodes the image file at `path` and uploads it via [`Texture::from_image`].
+    pub fn from_path(
+        device: &crate::renderer::RenderDevice,
+        queue: &crate::renderer::RenderQueue,
+        path: impl AsRef<std::path::Path>,
+        format: Option<TextureFormat>,
+    ) -> image::ImageResult<Self> {
+        let image = image::open(path)?;
+        Ok(Self::from_image(device, queue, &image, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_texture_descriptor_is_self_consistent() {
+        let desc = unknown_texture_descriptor();
+        assert_eq!(desc.size, Extent3d::default());
+        assert_eq!(desc.mip_level_count, 1);
+        assert_eq!(desc.sample_count, 1);
+        assert_eq!(desc.usage, TextureUsages::empty());
+        assert!(desc.view_formats.is_empty());
+    }
+
+    #[test]
+    fn srgb_and_linear_formats_round_trip() {
+        let pairs = [
+            (TextureFormat::Rgba8Unorm, TextureFormat::Rgba8UnormSrgb),
+            (TextureFormat::Bgra8Unorm, TextureFormat::Bgra8UnormSrgb),
+            (TextureFormat::Bc1RgbaUnorm, TextureFormat::Bc1RgbaUnormSrgb),
+            (TextureFormat::Bc2RgbaUnorm, TextureFormat::Bc2RgbaUnormSrgb),
+            (TextureFormat::Bc3RgbaUnorm, TextureFormat::Bc3RgbaUnormSrgb),
+            (TextureFormat::Bc7RgbaUnorm, TextureFormat::Bc7RgbaUnormSrgb),
+            (TextureFormat::Etc2Rgb8Unorm, TextureFormat::Etc2Rgb8UnormSrgb),
+            (
+                TextureFormat::Etc2Rgb8A1Unorm,
+                TextureFormat::Etc2Rgb8A1UnormSrgb,
+            ),
+            (
+                TextureFormat::Etc2Rgba8Unorm,
+                TextureFormat::Etc2Rgba8UnormSrgb,
+            ),
+        ];
+
+        for (linear, srgb) in pairs {
+            assert_eq!(srgb_format(linear), Some(srgb));
+            assert_eq!(linear_format(srgb), Some(linear));
+        }
+    }
+
+    #[test]
+    fn formats_without_a_sibling_return_none() {
+        assert_eq!(srgb_format(TextureFormat::Depth32Float), None);
+        assert_eq!(linear_format(TextureFormat::Depth32Float), None);
+        assert_eq!(srgb_format(TextureFormat::R8Unorm), None);
+        assert_eq!(linear_format(TextureFormat::R8Unorm), None);
+    }
+
+    #[test]
+    fn supports_mipmap_generation_rejects_depth_and_non_filterable_formats() {
+        assert!(supports_mipmap_generation(TextureFormat::Rgba8UnormSrgb));
+        assert!(supports_mipmap_generation(TextureFormat::Rgba16Float));
+        assert!(!supports_mipmap_generation(TextureFormat::Depth32Float));
+        assert!(!supports_mipmap_generation(TextureFormat::Depth24PlusStencil8));
+        assert!(!supports_mipmap_generation(TextureFormat::Rgba8Uint));
+        assert!(!supports_mipmap_generation(TextureFormat::Rgba32Float));
+    }
+
+    #[test]
+    fn floored_mip_size_halves_and_floors_to_one() {
+        assert_eq!(floored_mip_size(256, 0), 256);
+        assert_eq!(floored_mip_size(256, 1), 128);
+        assert_eq!(floored_mip_size(256, 8), 1);
+        // Non-power-of-two: floors rather than rounding.
+        assert_eq!(floored_mip_size(5, 1), 2);
+        assert_eq!(floored_mip_size(3, 2), 1);
+        assert_eq!(floored_mip_size(1, 10), 1);
+    }
+}
@@ -0,0 +1,3 @@
+mod render_device;
+
+pub use render_device::{RenderDevice, RenderQueue};
@@ -0,0 +1,63 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::render_resource::{Sampler, Texture};
+
+/// A wrapper around the GPU [`Device`](wgpu::Device) used to create render resources.
+///
+/// Its `create_*` methods mirror the raw wgpu API but wrap the results in this crate's own
+/// resource types (e.g. [`Texture`], [`Sampler`]) so that creation-time metadata (descriptors,
+/// ids) travels with the handle instead of being discarded.
+#[derive(Clone)]
+pub struct RenderDevice {
+    device: Arc<wgpu::Device>,
+}
+
+impl From<Arc<wgpu::Device>> for RenderDevice {
+    fn from(device: Arc<wgpu::Device>) -> Self {
+        RenderDevice { device }
+    }
+}
+
+impl RenderDevice {
+    /// Returns the raw wgpu [`Device`](wgpu::Device).
+    #[inline]
+    pub fn wgpu_device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    /// Creates a [`Texture`], carrying `desc` along with it so its size/format/usage can be
+    /// queried later without round-tripping to wgpu.
+    pub fn create_texture(&self, desc: &wgpu::TextureDescriptor<'static>) -> Texture {
+        let wgpu_texture = self.device.create_texture(desc);
+        Texture::from_descriptor(wgpu_texture, desc.clone())
+    }
+
+    /// Creates a [`Sampler`].
+    pub fn create_sampler(&self, desc: &wgpu::SamplerDescriptor) -> Sampler {
+        Sampler::from(self.device.create_sampler(desc))
+    }
+}
+
+/// A wrapper around the GPU [`Queue`](wgpu::Queue), used to submit command buffers and upload
+/// data to buffers/textures.
+///
+/// Dereferences to the wrapped wgpu [`Queue`](wgpu::Queue), so the usual `write_texture`,
+/// `write_buffer`, and `submit` methods are called directly on it.
+#[derive(Clone)]
+pub struct RenderQueue(pub Arc<wgpu::Queue>);
+
+impl From<Arc<wgpu::Queue>> for RenderQueue {
+    fn from(queue: Arc<wgpu::Queue>) -> Self {
+        RenderQueue(queue)
+    }
+}
+
+impl Deref for RenderQueue {
+    type Target = wgpu::Queue;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}